@@ -65,6 +65,7 @@ let
             "PORT=${toString icfg.port}"
             "FETCH_INTERVAL=${toString icfg.fetchInterval}"
             "PAGE_SIZE=${toString icfg.pageSize}"
+            "DATA_DIR=/var/lib/mean-feeder/${name}"
           ] ++ lib.optional (icfg.feeds != [])
             "FEEDS_FILE=${feedsFile}"
           ++ lib.optional (icfg.noisyFeeds != [])
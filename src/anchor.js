@@ -0,0 +1,14 @@
+(function(){
+  // Runs after the pager and the firehose state are restored: open whatever
+  // <details> hide the linked entry, then scroll to it.
+  function reveal() {
+    var m = location.hash.match(/^#(entry-[0-9a-f]+)$/);
+    var el = m && document.getElementById(m[1]);
+    if (!el) return;
+    for (var d = el.parentElement; d; d = d.parentElement)
+      if (d.tagName === 'DETAILS') d.open = true;
+    el.scrollIntoView();
+  }
+  reveal();
+  window.addEventListener('hashchange', reveal);
+})();
@@ -0,0 +1,13 @@
+(function(){
+  var toggle = document.getElementById('compact');
+  if (!toggle) return;
+  try {
+    var saved = localStorage.getItem('compact');
+    if (saved !== null) document.body.classList.toggle('compact', saved === '1');
+  } catch (e) {}
+  toggle.checked = document.body.classList.contains('compact');
+  toggle.addEventListener('change', function(){
+    document.body.classList.toggle('compact', toggle.checked);
+    try { localStorage.setItem('compact', toggle.checked ? '1' : '0'); } catch (e) {}
+  });
+})();
@@ -0,0 +1,11 @@
+(function(){
+  var details = document.getElementById('firehose');
+  if (!details) return;
+  try {
+    var saved = localStorage.getItem('firehose-open');
+    if (saved !== null) details.open = saved === '1';
+  } catch (e) {}
+  details.addEventListener('toggle', function(){
+    try { localStorage.setItem('firehose-open', details.open ? '1' : '0'); } catch (e) {}
+  });
+})();
@@ -6,6 +6,11 @@
     });
     return h;
   }
+  // A deep link to an entry, as #entry-<hash>.
+  function anchored() {
+    var m = location.hash.match(/^#(entry-[0-9a-f]+)$/);
+    return m ? document.getElementById(m[1]) : null;
+  }
   function setHash(h) {
     var parts = [];
     for (var k in h) parts.push(k+'='+h[k]);
@@ -17,11 +22,11 @@
     var pages = container.querySelectorAll('.page');
     if (!pages.length) return;
     var total = pages.length;
-    function show(p) {
+    function show(p, keepHash) {
       p = Math.max(1, Math.min(p, total));
       for (var i = 0; i < pages.length; i++)
         pages[i].style.display = (i === p - 1) ? '' : 'none';
-      var h = parseHash(); h[hashKey] = p; setHash(h);
+      if (!keepHash) { var h = parseHash(); h[hashKey] = p; setHash(h); }
       var pager = document.getElementById(pagerId);
       pager.innerHTML = '';
       if (p > 1) {
@@ -42,8 +47,17 @@
         pager.appendChild(next);
       }
     }
-    show(parseHash()[hashKey] || 1);
-    window.addEventListener('hashchange', function(){ show(parseHash()[hashKey] || 1); });
+    // On a deep link, open the page holding the entry and leave the hash
+    // alone so the browser can scroll to it.
+    function restore() {
+      var el = anchored();
+      if (!el) return show(parseHash()[hashKey] || 1);
+      var page = el.closest('.page');
+      var p = page && container.contains(page) ? parseInt(page.getAttribute('data-page'), 10) : 1;
+      show(p, true);
+    }
+    restore();
+    window.addEventListener('hashchange', restore);
   }
   paginate('main-entries','pager','page');
   paginate('noisy-entries','noisy-pager','noisy');
@@ -0,0 +1,29 @@
+(function(){
+  // Remembers the pages and scroll position of each view, and returns to
+  // them when the view is opened again without a hash. Runs before the
+  // pager so a restored hash is what it starts from.
+  var key = 'position:' + location.pathname + location.search;
+  function pagesHash() {
+    return /^#(page|noisy)=/.test(location.hash) ? location.hash : '';
+  }
+  function save() {
+    try {
+      localStorage.setItem(key, JSON.stringify({hash: pagesHash(), y: window.scrollY}));
+    } catch (e) {}
+  }
+  var saved = null;
+  if (!location.hash) {
+    try { saved = JSON.parse(localStorage.getItem(key)); } catch (e) {}
+  }
+  if (saved) {
+    if (saved.hash) history.replaceState(null, '', saved.hash);
+    // After the pager and the firehose state have laid the page out.
+    setTimeout(function(){ window.scrollTo(0, saved.y || 0); }, 0);
+  }
+  var timer = null;
+  window.addEventListener('scroll', function(){
+    clearTimeout(timer);
+    timer = setTimeout(save, 200);
+  });
+  window.addEventListener('hashchange', save);
+})();
@@ -0,0 +1,38 @@
+(function(){
+  var MAX_VISITED = 2000;
+  function load(key, fallback) {
+    try { var v = localStorage.getItem(key); return v === null ? fallback : JSON.parse(v); }
+    catch (e) { return fallback; }
+  }
+  function store(key, value) {
+    try { localStorage.setItem(key, JSON.stringify(value)); } catch (e) {}
+  }
+  var visited = load('visited', []);
+  var seen = {};
+  visited.forEach(function(href){ seen[href] = true; });
+  function link(entry) { return entry.querySelector('.header .title > a'); }
+  var entries = document.querySelectorAll('.entry');
+  for (var i = 0; i < entries.length; i++) {
+    var a = link(entries[i]);
+    if (a && seen[a.href]) entries[i].classList.add('read');
+  }
+  function markRead(e) {
+    var a = e.target.closest('.entry .header .title > a');
+    if (!a || seen[a.href]) return;
+    seen[a.href] = true;
+    visited.push(a.href);
+    if (visited.length > MAX_VISITED) visited = visited.slice(-MAX_VISITED);
+    store('visited', visited);
+    a.closest('.entry').classList.add('read');
+  }
+  document.addEventListener('click', markRead);
+  document.addEventListener('auxclick', markRead);
+  var toggle = document.getElementById('hide-read');
+  if (!toggle) return;
+  toggle.checked = load('hide-read', false);
+  document.body.classList.toggle('hide-read', toggle.checked);
+  toggle.addEventListener('change', function(){
+    document.body.classList.toggle('hide-read', toggle.checked);
+    store('hide-read', toggle.checked);
+  });
+})();
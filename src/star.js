@@ -0,0 +1,20 @@
+(function(){
+  document.addEventListener('click', function(e){
+    var btn = e.target.closest('button.star');
+    if (!btn) return;
+    e.preventDefault();
+    fetch('/star?id=' + encodeURIComponent(btn.dataset.id), {method: 'POST'})
+      .then(function(r){
+        if (!r.ok) throw new Error(r.status + ' ' + r.statusText);
+        return r.json();
+      })
+      .then(function(res){
+        btn.classList.toggle('starred', res.starred);
+        btn.innerHTML = res.starred ? '&#9733;' : '&#9734;';
+        btn.title = 'Star';
+      })
+      .catch(function(err){
+        btn.title = 'Could not star: ' + err.message;
+      });
+  });
+})();
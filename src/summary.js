@@ -0,0 +1,12 @@
+(function(){
+  document.addEventListener('click', function(e){
+    var btn = e.target.closest('.summary button.more');
+    if (!btn) return;
+    var summary = btn.parentNode;
+    var full = summary.querySelector('.summary-full');
+    var expand = full.hidden;
+    full.hidden = !expand;
+    summary.querySelector('.summary-preview').hidden = expand;
+    btn.textContent = expand ? 'less' : 'more';
+  });
+})();